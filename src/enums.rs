@@ -95,6 +95,50 @@ fn d() {
 
 }
 
+// IpAddress2 is close enough to std::net::IpAddr that it's worth giving it similar parsing and
+// formatting behavior: a `V4` string splits on `.` into four octets, anything with a `:` is
+// stored as `V6` without further validation, and `to_std` converts either variant into the real
+// std::net type (returning None when a stored `V6` string is not a valid IPv6 address).
+impl IpAddress2 {
+    // The bare name `Option` is permanently shadowed in this module by the hand-rolled
+    // `enum Option<T>` defined further down, so std's Option has to be named explicitly here.
+    fn parse(s: &str) -> std::option::Option<IpAddress2> {
+        if s.contains(':') {
+            return std::option::Option::Some(IpAddress2::V6(s.to_string()));
+        }
+
+        let octets: Vec<&str> = s.split('.').collect();
+        if octets.len() != 4 {
+            return std::option::Option::None;
+        }
+
+        let mut parsed = [0u8; 4];
+        for (i, octet) in octets.iter().enumerate() {
+            parsed[i] = octet.parse().ok()?;
+        }
+
+        std::option::Option::Some(IpAddress2::V4(parsed[0], parsed[1], parsed[2], parsed[3]))
+    }
+
+    fn to_std(&self) -> std::option::Option<std::net::IpAddr> {
+        match self {
+            IpAddress2::V4(a, b, c, d) => std::option::Option::Some(std::net::IpAddr::V4(
+                std::net::Ipv4Addr::new(*a, *b, *c, *d),
+            )),
+            IpAddress2::V6(s) => s.parse().ok().map(std::net::IpAddr::V6),
+        }
+    }
+}
+
+impl std::fmt::Display for IpAddress2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IpAddress2::V4(a, b, c, d) => write!(f, "{}.{}.{}.{}", a, b, c, d),
+            IpAddress2::V6(s) => write!(f, "{}", s),
+        }
+    }
+}
+
 // We’ve shown several different ways to define data structures to store version four and version six IP addresses.
 //
 // However, as it turns out, wanting to store IP addresses and encode which kind they are is so common
@@ -118,15 +162,46 @@ enum Message {
 // There is one more similarity between enums and structs:
 // just as we’re able to define methods on structs using impl, we’re also able to define methods on enums.
 // Here’s a method named call that we could define on our Message enum:
+// Canvas is the state a Message is interpreted against: Move updates the cursor, ChangeColor
+// updates the color, Write appends to the log, and Quit stops the canvas from running.
+struct Canvas {
+    cursor: (i32, i32),
+    color: (i32, i32, i32),
+    running: bool,
+    log: Vec<String>,
+}
+
+impl Canvas {
+    fn new() -> Canvas {
+        Canvas {
+            cursor: (0, 0),
+            color: (0, 0, 0),
+            running: true,
+            log: Vec::new(),
+        }
+    }
+
+    fn apply(&mut self, msg: &Message) {
+        match msg {
+            Message::Quit => self.running = false,
+            Message::Move { x, y } => self.cursor = (*x, *y),
+            Message::Write(text) => self.log.push(text.clone()),
+            Message::ChangeColor(r, g, b) => self.color = (*r, *g, *b),
+        }
+    }
+}
+
 impl Message {
-    fn call(&self) {
-        // method body would be defined here
+    fn call(&self, canvas: &mut Canvas) {
+        canvas.apply(self);
     }
 }
 
 fn e() {
+    let mut canvas = Canvas::new();
+
     let m = Message::Write(String::from("hello"));
-    m.call(); // WOW enum with methods ...
+    m.call(&mut canvas); // WOW enum with methods ...
 }
 
 // The Option Enum and Its Advantages Over Null Values ---
@@ -153,11 +228,77 @@ fn e() {
 
 // This enum is Option<T>, and it is defined by the standard library as follows:
 enum Option<T> {
-    Some(t),
+    Some(T),
     None,
 }
 // that  is f... awesome
 
+// Combinators on our own Option<T> ---
+// The standard library's Option earns its keep through methods like `map` and `and_then` that let
+// you chain transformations without manually unwrapping at every step. Our hand-written version
+// gets the same treatment here, each one implemented with a `match` over the two variants.
+impl<T> Option<T> {
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> Option<U> {
+        match self {
+            Option::Some(value) => Option::Some(f(value)),
+            Option::None => Option::None,
+        }
+    }
+
+    fn and_then<U>(self, f: impl FnOnce(T) -> Option<U>) -> Option<U> {
+        match self {
+            Option::Some(value) => f(value),
+            Option::None => Option::None,
+        }
+    }
+
+    fn unwrap_or(self, default: T) -> T {
+        match self {
+            Option::Some(value) => value,
+            Option::None => default,
+        }
+    }
+
+    fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Option::Some(value) => Ok(value),
+            Option::None => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod option_tests {
+    use super::Option;
+
+    #[test]
+    fn map_transforms_some_and_leaves_none_alone() {
+        assert!(matches!(Option::Some(2).map(|n| n * 2), Option::Some(4)));
+        assert!(matches!(Option::None::<i32>.map(|n: i32| n * 2), Option::None));
+    }
+
+    #[test]
+    fn and_then_chains_fallible_steps() {
+        let half = |n: i32| if n % 2 == 0 { Option::Some(n / 2) } else { Option::None };
+
+        assert!(matches!(Option::Some(4).and_then(half), Option::Some(2)));
+        assert!(matches!(Option::Some(3).and_then(half), Option::None));
+        assert!(matches!(Option::None::<i32>.and_then(half), Option::None));
+    }
+
+    #[test]
+    fn unwrap_or_falls_back_on_none() {
+        assert_eq!(Option::Some(5).unwrap_or(0), 5);
+        assert_eq!(Option::None::<i32>.unwrap_or(0), 0);
+    }
+
+    #[test]
+    fn ok_or_converts_to_result() {
+        assert_eq!(Option::Some(5).ok_or("missing"), Ok(5));
+        assert_eq!(Option::None::<i32>.ok_or("missing"), Err("missing"));
+    }
+}
+
 // The Option<T> enum is so useful that it’s even included in the prelude; you don’t need to bring it into scope explicitly.
 // In addition, so are its variants: you can use Some and None directly without the Option:: prefix.
 // The Option<T> enum is still just a regular enum, and Some(T) and None are still variants of type Option<T>.
@@ -212,4 +353,38 @@ fn g() {
 // You want some other code to run if you have a None value, and that code doesn’t have a T value available.
 // The match expression is a control flow construct that does just this when used with enums:
 // it will run different code depending on which variant of the enum it has,
-// and that code can use the data inside the matching value.
\ No newline at end of file
+// and that code can use the data inside the matching value.
+
+// Struct-style variants in practice ---
+// The IpAddr and Message examples above hint at struct-like and tuple-like variants; a shape
+// enum shows the struct-like kind in use: every variant carries named fields, and `match`
+// destructures them by name, skipping the fields a method doesn't need with `..`.
+enum Shape {
+    Rectangle { width: f64, height: f64 },
+    Circle { radius: f64 },
+    Triangle {
+        base: f64,
+        height: f64,
+        a: f64,
+        b: f64,
+        c: f64,
+    },
+}
+
+impl Shape {
+    fn area(&self) -> f64 {
+        match self {
+            Shape::Rectangle { width, height } => width * height,
+            Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+            Shape::Triangle { base, height, .. } => 0.5 * base * height,
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        match self {
+            Shape::Rectangle { width, height } => 2.0 * (width + height),
+            Shape::Circle { radius } => 2.0 * std::f64::consts::PI * radius,
+            Shape::Triangle { a, b, c, .. } => a + b + c,
+        }
+    }
+}
\ No newline at end of file